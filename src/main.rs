@@ -1,11 +1,14 @@
 use clap::{Arg, ArgAction, Command};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::io::{Result, Write};
 use std::os::unix::fs::PermissionsExt;
 use std::path::{Path, PathBuf};
 use std::process::{Command as ProcessCommand, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 fn main() -> Result<()> {
     let matches = Command::new("eph")
@@ -38,6 +41,102 @@ fn main() -> Result<()> {
                 .value_name("SCRIPT")
                 .help("Delete a script"),
         )
+        .arg(
+            Arg::new("editor")
+                .long("editor")
+                .num_args(1)
+                .value_name("CMD")
+                .help("Editor to use for this invocation, overriding config.toml and $VISUAL/$EDITOR"),
+        )
+        .arg(
+            Arg::new("restore")
+                .long("restore")
+                .num_args(1)
+                .value_name("SCRIPT")
+                .help("Restore a script from trash"),
+        )
+        .arg(
+            Arg::new("list-trash")
+                .long("list-trash")
+                .help("List trashed scripts")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("empty-trash")
+                .long("empty-trash")
+                .help("Permanently delete everything in trash")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("completions")
+                .long("completions")
+                .num_args(1)
+                .value_name("SHELL")
+                .value_parser(["bash", "zsh", "fish"])
+                .help("Print a shell completion script for bash, zsh, or fish"),
+        )
+        .arg(
+            Arg::new("__complete")
+                .long("__complete")
+                .num_args(1)
+                .value_name("PREFIX")
+                .hide(true)
+                .help("Print script names matching PREFIX, one per line"),
+        )
+        .arg(
+            Arg::new("add-repo")
+                .long("add-repo")
+                .num_args(1)
+                .value_name("GIT_URL")
+                .help("Clone a git repository of scripts into repos/<name>"),
+        )
+        .arg(
+            Arg::new("update-repos")
+                .long("update-repos")
+                .help("Pull all tracked script repos")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("export")
+                .long("export")
+                .num_args(1)
+                .value_name("FILE.tar.xz")
+                .help("Export all scripts into a compressed tar archive"),
+        )
+        .arg(
+            Arg::new("import")
+                .long("import")
+                .num_args(1)
+                .value_name("FILE.tar.xz")
+                .help("Import scripts from a compressed tar archive"),
+        )
+        .arg(
+            Arg::new("with-config")
+                .long("with-config")
+                .help("Include config.toml when exporting")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .help("Overwrite existing scripts on import")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .num_args(1)
+                .value_name("SECS")
+                .value_parser(clap::value_parser!(u64))
+                .help("Kill the running script after SECS seconds"),
+        )
+        .arg(
+            Arg::new("cwd")
+                .long("cwd")
+                .num_args(1)
+                .value_name("DIR")
+                .help("Run the script with DIR as its working directory"),
+        )
         .arg(Arg::new("script").help("Script to run").index(1))
         .arg(
             Arg::new("args")
@@ -65,19 +164,54 @@ fn main() -> Result<()> {
     };
     fs::create_dir_all(&script_dir)?;
 
-    if matches.get_flag("list") {
-        list_scripts(&script_dir)?;
+    if let Some(shell) = matches.get_one::<String>("completions") {
+        print_completions(shell);
+    } else if let Some(prefix) = matches.get_one::<String>("__complete") {
+        for script in list_scripts(&script_dir)? {
+            if script.starts_with(prefix.as_str()) {
+                println!("{}", script);
+            }
+        }
+    } else if matches.get_flag("list") {
+        print_scripts(&script_dir, &list_scripts(&script_dir)?)?;
     } else if let Some(script_name) = matches.get_one::<String>("edit") {
-        edit_script(script_dir, script_name, &config)?;
+        let editor_override = matches.get_one::<String>("editor");
+        edit_script(script_dir, script_name, &config, editor_override)?;
     } else if let Some(script_name) = matches.get_one::<String>("new") {
-        create_script(script_dir, script_name, &config)?;
+        let editor_override = matches.get_one::<String>("editor");
+        create_script(script_dir, script_name, &config, editor_override)?;
     } else if let Some(script_name) = matches.get_one::<String>("delete") {
         delete_script(script_dir, script_name)?;
+    } else if let Some(script_name) = matches.get_one::<String>("restore") {
+        restore_script(script_dir, script_name)?;
+    } else if matches.get_flag("list-trash") {
+        list_trash(&script_dir)?;
+    } else if matches.get_flag("empty-trash") {
+        empty_trash(&script_dir)?;
+    } else if let Some(dest) = matches.get_one::<String>("export") {
+        let with_config = matches.get_flag("with-config");
+        export_scripts(&script_dir, Path::new(dest), &config_file_path, with_config)?;
+    } else if let Some(src) = matches.get_one::<String>("import") {
+        let force = matches.get_flag("force");
+        import_scripts(&script_dir, Path::new(src), &config_file_path, force)?;
+    } else if let Some(url) = matches.get_one::<String>("add-repo") {
+        add_repo(&script_dir, &config_file_path, config, url)?;
+    } else if matches.get_flag("update-repos") {
+        update_repos(&script_dir, &config)?;
     } else if let Some(script_name) = matches.get_one::<String>("script") {
         let script_args: Vec<&String> = matches
             .get_many::<String>("args")
             .map_or(vec![], |vals| vals.collect());
-        run_script(script_dir, script_name, &script_args)?;
+        let timeout = matches
+            .get_one::<u64>("timeout")
+            .copied()
+            .or(config.timeout_secs)
+            .map(Duration::from_secs);
+        let cwd = matches
+            .get_one::<String>("cwd")
+            .map(PathBuf::from)
+            .map_or_else(env::current_dir, Ok)?;
+        run_script(script_dir, script_name, &script_args, cwd, timeout)?;
     } else {
         eprintln!("No valid command provided. Use --help for usage.");
     }
@@ -89,6 +223,10 @@ fn main() -> Result<()> {
 struct Config {
     editor: Option<String>,
     script_dir: Option<String>,
+    #[serde(default)]
+    timeout_secs: Option<u64>,
+    #[serde(default)]
+    repos: Vec<String>,
 }
 
 fn load_or_create_config(config_file_path: &Path) -> Result<Config> {
@@ -98,26 +236,41 @@ fn load_or_create_config(config_file_path: &Path) -> Result<Config> {
         Ok(config)
     } else {
         let default_config = Config {
-            editor: Some("nano".to_string()),
+            editor: None,
             script_dir: None,
+            timeout_secs: None,
+            repos: Vec::new(),
         };
-        let toml_string = toml::to_string_pretty(&default_config).unwrap();
-        let mut file = fs::File::create(config_file_path)?;
-        file.write_all(toml_string.as_bytes())?;
+        save_config(config_file_path, &default_config)?;
         Ok(default_config)
     }
 }
 
-fn edit_script(script_dir: PathBuf, script_name: &str, config: &Config) -> Result<()> {
+fn save_config(config_file_path: &Path, config: &Config) -> Result<()> {
+    let toml_string = toml::to_string_pretty(config).unwrap();
+    fs::File::create(config_file_path)?.write_all(toml_string.as_bytes())
+}
+
+fn edit_script(
+    script_dir: PathBuf,
+    script_name: &str,
+    config: &Config,
+    editor_override: Option<&String>,
+) -> Result<()> {
     let script_path = script_dir.join(script_name);
     if !script_path.exists() {
         eprintln!("Script does not exist. Use -n to create a new script.");
         return Ok(());
     }
-    open_in_editor(script_path, config)
+    open_in_editor(script_path, config, editor_override)
 }
 
-fn create_script(script_dir: PathBuf, script_name: &str, config: &Config) -> Result<()> {
+fn create_script(
+    script_dir: PathBuf,
+    script_name: &str,
+    config: &Config,
+    editor_override: Option<&String>,
+) -> Result<()> {
     let script_path = script_dir.join(script_name);
     if script_path.exists() {
         eprintln!("Script already exists. Use -e to edit.");
@@ -127,16 +280,31 @@ fn create_script(script_dir: PathBuf, script_name: &str, config: &Config) -> Res
     let mut perms = fs::metadata(&script_path)?.permissions();
     perms.set_mode(0o755);
     fs::set_permissions(&script_path, perms)?;
-    open_in_editor(script_path, config)
+    open_in_editor(script_path, config, editor_override)
 }
 
 fn delete_script(script_dir: PathBuf, script_name: &str) -> Result<()> {
+    if script_name == "repos" || script_name == "trash" {
+        eprintln!("'{}' is a reserved directory, not a script.", script_name);
+        return Ok(());
+    }
     let script_path = script_dir.join(script_name);
-    if script_path.exists() {
+    if script_path.is_file() {
         let trash_dir = script_dir.join("trash");
         fs::create_dir_all(&trash_dir)?;
         let trash_path = trash_dir.join(script_name);
         fs::rename(&script_path, &trash_path)?;
+
+        let meta_path = trash_dir.join(".meta.toml");
+        let mut meta = load_trash_meta(&meta_path)?;
+        meta.entries.insert(
+            script_name.to_string(),
+            TrashEntry {
+                deleted_at: unix_now(),
+            },
+        );
+        save_trash_meta(&meta_path, &meta)?;
+
         println!("Script '{}' moved to trash.", script_name);
     } else {
         eprintln!("Script '{}' does not exist.", script_name);
@@ -144,36 +312,321 @@ fn delete_script(script_dir: PathBuf, script_name: &str) -> Result<()> {
     Ok(())
 }
 
-fn run_script(script_dir: PathBuf, script_name: &str, args: &[&String]) -> Result<()> {
+fn restore_script(script_dir: PathBuf, script_name: &str) -> Result<()> {
+    if script_name == "repos" || script_name == "trash" {
+        eprintln!("'{}' is a reserved directory, not a script.", script_name);
+        return Ok(());
+    }
+    let trash_dir = script_dir.join("trash");
+    let trash_path = trash_dir.join(script_name);
+    if !trash_path.is_file() {
+        eprintln!("Script '{}' is not in trash.", script_name);
+        return Ok(());
+    }
     let script_path = script_dir.join(script_name);
-    if !script_path.exists() {
-        eprintln!("Script '{}' does not exist.", script_name);
+    if script_path.exists() {
+        eprintln!(
+            "Script '{}' already exists. Remove or rename it before restoring.",
+            script_name
+        );
         return Ok(());
     }
-    let current_dir = env::current_dir()?;
-    let status = ProcessCommand::new(&script_path)
+    fs::rename(&trash_path, &script_path)?;
+
+    let meta_path = trash_dir.join(".meta.toml");
+    let mut meta = load_trash_meta(&meta_path)?;
+    meta.entries.remove(script_name);
+    save_trash_meta(&meta_path, &meta)?;
+
+    println!("Script '{}' restored.", script_name);
+    Ok(())
+}
+
+fn list_trash(script_dir: &Path) -> Result<()> {
+    let trash_dir = script_dir.join("trash");
+    let meta = load_trash_meta(&trash_dir.join(".meta.toml"))?;
+    let scripts = list_scripts(&trash_dir)?;
+
+    if scripts.is_empty() {
+        println!("Trash is empty.");
+    } else {
+        println!("Trashed scripts:");
+        for script in scripts {
+            match meta.entries.get(&script) {
+                Some(entry) => println!("- {} (deleted at {})", script, entry.deleted_at),
+                None => println!("- {}", script),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn empty_trash(script_dir: &Path) -> Result<()> {
+    let trash_dir = script_dir.join("trash");
+    if !trash_dir.exists() {
+        println!("Trash is empty.");
+        return Ok(());
+    }
+    for script in list_scripts(&trash_dir)? {
+        fs::remove_file(trash_dir.join(script))?;
+    }
+    let meta_path = trash_dir.join(".meta.toml");
+    if meta_path.exists() {
+        fs::remove_file(meta_path)?;
+    }
+    println!("Trash emptied.");
+    Ok(())
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct TrashMeta {
+    #[serde(default)]
+    entries: HashMap<String, TrashEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TrashEntry {
+    deleted_at: u64,
+}
+
+fn load_trash_meta(meta_path: &Path) -> Result<TrashMeta> {
+    if meta_path.exists() {
+        let contents = fs::read_to_string(meta_path)?;
+        Ok(toml::from_str(&contents)?)
+    } else {
+        Ok(TrashMeta::default())
+    }
+}
+
+fn save_trash_meta(meta_path: &Path, meta: &TrashMeta) -> Result<()> {
+    let toml_string = toml::to_string_pretty(meta).unwrap();
+    fs::write(meta_path, toml_string)
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+const TIMEOUT_KILL_GRACE: Duration = Duration::from_secs(5);
+
+fn run_script(
+    script_dir: PathBuf,
+    script_name: &str,
+    args: &[&String],
+    cwd: PathBuf,
+    timeout: Option<Duration>,
+) -> Result<()> {
+    let script_path = match resolve_script_path(&script_dir, script_name) {
+        Some(path) => path,
+        None => match resolve_alias(&script_dir, script_name)? {
+            Some(real_name) => match resolve_script_path(&script_dir, &real_name) {
+                Some(path) => path,
+                None => {
+                    eprintln!("Script '{}' does not exist.", script_name);
+                    return Ok(());
+                }
+            },
+            None => {
+                eprintln!("Script '{}' does not exist.", script_name);
+                return Ok(());
+            }
+        },
+    };
+
+    let meta = parse_frontmatter(&script_path)?;
+    if args.len() < meta.args.len() {
+        print_usage(script_name, &meta);
+        return Ok(());
+    }
+
+    let mut child = ProcessCommand::new(&script_path)
         .args(args)
-        .current_dir(current_dir)
+        .current_dir(cwd)
         .stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .status()?;
-    if !status.success() {
-        eprintln!("Script exited with status: {}", status);
+        .spawn()?;
+
+    let Some(timeout) = timeout else {
+        let status = child.wait()?;
+        if !status.success() {
+            eprintln!("Script exited with status: {}", status);
+        }
+        return Ok(());
+    };
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            if !status.success() {
+                eprintln!("Script exited with status: {}", status);
+            }
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            break;
+        }
+        thread::sleep(Duration::from_millis(50));
     }
+
+    eprintln!(
+        "Script '{}' timed out after {}s, sending SIGTERM.",
+        script_name,
+        timeout.as_secs()
+    );
+    send_signal(child.id(), libc::SIGTERM);
+
+    let grace_deadline = Instant::now() + TIMEOUT_KILL_GRACE;
+    loop {
+        if child.try_wait()?.is_some() {
+            eprintln!("script timed out");
+            std::process::exit(1);
+        }
+        if Instant::now() >= grace_deadline {
+            eprintln!("Script '{}' still running, sending SIGKILL.", script_name);
+            send_signal(child.id(), libc::SIGKILL);
+            child.wait()?;
+            eprintln!("script timed out");
+            std::process::exit(1);
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn send_signal(pid: u32, signal: libc::c_int) {
+    unsafe {
+        libc::kill(pid as libc::pid_t, signal);
+    }
+}
+
+/// xz preset 9 with the "extreme" flag (`LZMA_PRESET_EXTREME`), a generous
+/// compression window well suited to the mostly-text contents of a script
+/// collection.
+const EXPORT_XZ_PRESET: u32 = 9 | (1 << 31);
+
+fn export_scripts(
+    script_dir: &Path,
+    dest: &Path,
+    config_file_path: &Path,
+    with_config: bool,
+) -> Result<()> {
+    let file = fs::File::create(dest)?;
+    let encoder = xz2::write::XzEncoder::new(file, EXPORT_XZ_PRESET);
+    let mut builder = tar::Builder::new(encoder);
+
+    for script in list_scripts(script_dir)? {
+        let Some(script_path) = resolve_script_path(script_dir, &script) else {
+            continue;
+        };
+        builder.append_path_with_name(script_path, &script)?;
+    }
+    if with_config && config_file_path.exists() {
+        builder.append_path_with_name(config_file_path, "config.toml")?;
+    }
+
+    builder.into_inner()?.finish()?;
+    println!("Exported scripts to '{}'.", dest.display());
     Ok(())
 }
 
-fn open_in_editor(script_path: PathBuf, config: &Config) -> Result<()> {
-    let editor = config.editor.clone().unwrap_or_else(|| "nano".to_string());
-    let status = ProcessCommand::new(editor).arg(script_path).status()?;
+fn import_scripts(
+    script_dir: &Path,
+    src: &Path,
+    config_file_path: &Path,
+    force: bool,
+) -> Result<()> {
+    let file = fs::File::open(src)?;
+    let decoder = xz2::read::XzDecoder::new(file);
+    let mut archive = tar::Archive::new(decoder);
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_path = entry.path()?.into_owned();
+        if entry_path.components().any(|c| {
+            matches!(
+                c,
+                std::path::Component::ParentDir
+                    | std::path::Component::RootDir
+                    | std::path::Component::Prefix(_)
+            )
+        }) {
+            eprintln!(
+                "Skipping unsafe archive entry '{}'.",
+                entry_path.display()
+            );
+            continue;
+        }
+        let name = entry_path.to_string_lossy().into_owned();
+
+        let dest_path = if name == "config.toml" {
+            config_file_path.to_path_buf()
+        } else if entry_path.components().count() > 1 {
+            // Repo-namespaced scripts are exported under their display name
+            // ("<repo>/<script>") but live on disk under `repos/`.
+            script_dir.join("repos").join(&name)
+        } else {
+            script_dir.join(&name)
+        };
+
+        if dest_path.exists() && !force {
+            eprintln!("Skipping '{}': already exists (use --force to overwrite).", name);
+            continue;
+        }
+        entry.unpack(&dest_path)?;
+    }
+
+    println!("Imported scripts from '{}'.", src.display());
+    Ok(())
+}
+
+/// Resolves which editor to launch, in priority order: an explicit
+/// `--editor` override for this invocation, `config.editor`, `$VISUAL`,
+/// `$EDITOR`, then `vi` as a last resort.
+fn resolve_editor(config: &Config, editor_override: Option<&String>) -> String {
+    if let Some(editor) = editor_override {
+        return editor.clone();
+    }
+    if let Some(editor) = &config.editor {
+        return editor.clone();
+    }
+    if let Ok(editor) = env::var("VISUAL") {
+        if !editor.is_empty() {
+            return editor;
+        }
+    }
+    if let Ok(editor) = env::var("EDITOR") {
+        if !editor.is_empty() {
+            return editor;
+        }
+    }
+    "vi".to_string()
+}
+
+fn open_in_editor(
+    script_path: PathBuf,
+    config: &Config,
+    editor_override: Option<&String>,
+) -> Result<()> {
+    let editor = resolve_editor(config, editor_override);
+    let status = match ProcessCommand::new(&editor).arg(&script_path).status() {
+        Ok(status) => status,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound && editor != "nano" => {
+            eprintln!("Editor '{}' not found, falling back to nano.", editor);
+            ProcessCommand::new("nano").arg(&script_path).status()?
+        }
+        Err(err) => return Err(err),
+    };
     if !status.success() {
         eprintln!("Editor exited with status: {}", status);
     }
     Ok(())
 }
 
-fn list_scripts(script_dir: &PathBuf) -> Result<()> {
+fn list_scripts(script_dir: &Path) -> Result<Vec<String>> {
     let mut scripts = Vec::new();
 
     for entry in fs::read_dir(script_dir)? {
@@ -190,14 +643,323 @@ fn list_scripts(script_dir: &PathBuf) -> Result<()> {
         }
     }
 
+    let repos_dir = script_dir.join("repos");
+    if repos_dir.is_dir() {
+        for repo_entry in fs::read_dir(&repos_dir)? {
+            let repo_path = repo_entry?.path();
+            if !repo_path.is_dir() {
+                continue;
+            }
+            let Some(repo_name) = repo_path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            for entry in fs::read_dir(&repo_path)? {
+                let path = entry?.path();
+                if path.is_file() {
+                    if let Some(name_str) = path.file_name().and_then(|n| n.to_str()) {
+                        if !name_str.starts_with('.') {
+                            scripts.push(format!("{}/{}", repo_name, name_str));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    scripts.sort();
+    Ok(scripts)
+}
+
+/// Resolves a script's display name (as returned by `list_scripts`, e.g.
+/// `myscript` or `reponame/myscript`) to its real path on disk. Repo-namespaced
+/// scripts actually live under `repos/<name>`, a segment that `list_scripts`
+/// strips for display, so a plain `script_dir.join(name)` isn't enough.
+fn resolve_script_path(script_dir: &Path, name: &str) -> Option<PathBuf> {
+    let direct = script_dir.join(name);
+    if direct.is_file() {
+        return Some(direct);
+    }
+    let namespaced = script_dir.join("repos").join(name);
+    if namespaced.is_file() {
+        return Some(namespaced);
+    }
+    None
+}
+
+/// Derives a namespace directory name from a git URL, e.g.
+/// `https://github.com/foo/bar.git` -> `bar`.
+fn repo_name_from_url(url: &str) -> String {
+    let trimmed = url.trim_end_matches('/').trim_end_matches(".git");
+    trimmed.rsplit('/').next().unwrap_or(trimmed).to_string()
+}
+
+fn add_repo(
+    script_dir: &Path,
+    config_file_path: &Path,
+    mut config: Config,
+    url: &str,
+) -> Result<()> {
+    let name = repo_name_from_url(url);
+    let repos_dir = script_dir.join("repos");
+    fs::create_dir_all(&repos_dir)?;
+    let repo_path = repos_dir.join(&name);
+    if repo_path.exists() {
+        eprintln!(
+            "Repo '{}' already exists at '{}'.",
+            name,
+            repo_path.display()
+        );
+        return Ok(());
+    }
+
+    let status = ProcessCommand::new("git")
+        .args(["clone", url])
+        .arg(&repo_path)
+        .status()?;
+    if !status.success() {
+        eprintln!("Failed to clone '{}'.", url);
+        return Ok(());
+    }
+
+    if !config.repos.iter().any(|r| r == url) {
+        config.repos.push(url.to_string());
+        save_config(config_file_path, &config)?;
+    }
+    println!("Added repo '{}' as '{}'.", url, name);
+    Ok(())
+}
+
+fn update_repos(script_dir: &Path, config: &Config) -> Result<()> {
+    if config.repos.is_empty() {
+        println!("No tracked repos.");
+        return Ok(());
+    }
+
+    for url in &config.repos {
+        let name = repo_name_from_url(url);
+        let repo_path = script_dir.join("repos").join(&name);
+        if !repo_path.exists() {
+            eprintln!(
+                "Repo '{}' is tracked but missing on disk; re-run --add-repo.",
+                name
+            );
+            continue;
+        }
+        println!("Updating '{}'...", name);
+        let status = ProcessCommand::new("git")
+            .arg("pull")
+            .current_dir(&repo_path)
+            .status()?;
+        if !status.success() {
+            eprintln!("Failed to update '{}'.", name);
+        }
+    }
+    Ok(())
+}
+
+fn print_scripts(script_dir: &Path, scripts: &[String]) -> Result<()> {
     if scripts.is_empty() {
         println!("No scripts found.");
     } else {
         println!("Available scripts:");
         for script in scripts {
-            println!("- {}", script);
+            let Some(script_path) = resolve_script_path(script_dir, script) else {
+                println!("- {}", script);
+                continue;
+            };
+            let meta = parse_frontmatter(&script_path)?;
+            match meta.desc {
+                Some(desc) => println!("- {} — {}", script, desc),
+                None => println!("- {}", script),
+            }
         }
     }
-
     Ok(())
 }
+
+/// Metadata parsed from a script's leading comment lines (right after the
+/// shebang): `# @desc: ...`, `# @arg: name  description`, `# @alias: short`.
+#[derive(Default)]
+struct ScriptMeta {
+    desc: Option<String>,
+    args: Vec<ScriptArg>,
+    alias: Option<String>,
+}
+
+struct ScriptArg {
+    name: String,
+    desc: String,
+}
+
+fn parse_frontmatter(script_path: &Path) -> Result<ScriptMeta> {
+    let contents = match fs::read_to_string(script_path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!(
+                "Skipping frontmatter for '{}': {}",
+                script_path.display(),
+                err
+            );
+            return Ok(ScriptMeta::default());
+        }
+    };
+    let mut meta = ScriptMeta::default();
+
+    let mut lines = contents.lines().peekable();
+    if let Some(first) = lines.peek() {
+        if first.starts_with("#!") {
+            lines.next();
+        }
+    }
+
+    while let Some(line) = lines.peek() {
+        let line = line.trim();
+        if line.is_empty() {
+            // allow a blank line between the shebang and/or annotations, as
+            // left by `create_script`'s scaffold
+        } else if let Some(rest) = line.strip_prefix("# @desc:") {
+            meta.desc = Some(rest.trim().to_string());
+        } else if let Some(rest) = line.strip_prefix("# @arg:") {
+            let rest = rest.trim();
+            match rest.split_once(char::is_whitespace) {
+                Some((name, desc)) => meta.args.push(ScriptArg {
+                    name: name.trim().to_string(),
+                    desc: desc.trim().to_string(),
+                }),
+                None => meta.args.push(ScriptArg {
+                    name: rest.to_string(),
+                    desc: String::new(),
+                }),
+            }
+        } else if let Some(rest) = line.strip_prefix("# @alias:") {
+            meta.alias = Some(rest.trim().to_string());
+        } else {
+            break;
+        }
+        lines.next();
+    }
+
+    Ok(meta)
+}
+
+/// Builds a map of `@alias` -> script name as returned by `list_scripts`
+/// (e.g. `myscript` or `reponame/myscript`), much like the moros shell's
+/// `aliases` config, by scanning every script's frontmatter.
+fn build_alias_map(script_dir: &Path) -> Result<HashMap<String, String>> {
+    let mut aliases = HashMap::new();
+    for script in list_scripts(script_dir)? {
+        let Some(script_path) = resolve_script_path(script_dir, &script) else {
+            continue;
+        };
+        let meta = parse_frontmatter(&script_path)?;
+        if let Some(alias) = meta.alias {
+            aliases.insert(alias, script);
+        }
+    }
+    Ok(aliases)
+}
+
+fn resolve_alias(script_dir: &Path, alias: &str) -> Result<Option<String>> {
+    Ok(build_alias_map(script_dir)?.remove(alias))
+}
+
+fn print_usage(script_name: &str, meta: &ScriptMeta) {
+    let arg_names: Vec<&str> = meta.args.iter().map(|a| a.name.as_str()).collect();
+    eprintln!("Usage: eph {} {}", script_name, arg_names.join(" "));
+    for arg in &meta.args {
+        eprintln!("  {}  {}", arg.name, arg.desc);
+    }
+}
+
+/// Prints a completion script for the given shell. Each shell function shells
+/// out to `eph --__complete <word>` at completion time so candidates always
+/// reflect the current contents of `script_dir`.
+fn print_completions(shell: &str) {
+    match shell {
+        "bash" => println!(
+            r#"_eph_complete() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    COMPREPLY=($(eph --__complete "$cur"))
+}}
+complete -F _eph_complete eph"#
+        ),
+        "zsh" => println!(
+            r#"#compdef eph
+_eph() {{
+    local -a scripts
+    scripts=(${{(f)"$(eph --__complete "$PREFIX")"}})
+    compadd -a scripts
+}}
+compdef _eph eph"#
+        ),
+        "fish" => println!(r#"complete -c eph -f -a '(eph --__complete (commandline -ct))'"#),
+        _ => unreachable!("value_parser restricts shell to bash, zsh, fish"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("eph_test_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn build_archive(dest: &Path, entries: &[(&str, &str)]) {
+        let file = fs::File::create(dest).unwrap();
+        let encoder = xz2::write::XzEncoder::new(file, EXPORT_XZ_PRESET);
+        let mut builder = tar::Builder::new(encoder);
+        for (name, contents) in entries {
+            let mut header = tar::Header::new_gnu();
+            // Write the raw name bytes directly rather than going through
+            // `set_path`, which (rightly) refuses to create a `..` entry --
+            // exactly the kind of malicious archive these tests exercise.
+            header.as_gnu_mut().unwrap().name[..name.len()].copy_from_slice(name.as_bytes());
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o755);
+            header.set_cksum();
+            builder.append(&header, contents.as_bytes()).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn import_rejects_path_traversal() {
+        let root = temp_dir("traversal");
+        let script_dir = root.join("scripts");
+        fs::create_dir_all(&script_dir).unwrap();
+        let archive_path = root.join("bundle.tar.xz");
+        build_archive(&archive_path, &[("../evil.sh", "echo pwned\n")]);
+
+        import_scripts(&script_dir, &archive_path, &root.join("config.toml"), false).unwrap();
+
+        assert!(!root.join("evil.sh").exists());
+        assert!(list_scripts(&script_dir).unwrap().is_empty());
+    }
+
+    #[test]
+    fn import_skips_existing_without_force_and_overwrites_with_force() {
+        let root = temp_dir("collision");
+        let script_dir = root.join("scripts");
+        fs::create_dir_all(&script_dir).unwrap();
+        fs::write(script_dir.join("greet.sh"), "echo old\n").unwrap();
+
+        let archive_path = root.join("bundle.tar.xz");
+        build_archive(&archive_path, &[("greet.sh", "echo new\n")]);
+
+        import_scripts(&script_dir, &archive_path, &root.join("config.toml"), false).unwrap();
+        assert_eq!(
+            fs::read_to_string(script_dir.join("greet.sh")).unwrap(),
+            "echo old\n"
+        );
+
+        import_scripts(&script_dir, &archive_path, &root.join("config.toml"), true).unwrap();
+        assert_eq!(
+            fs::read_to_string(script_dir.join("greet.sh")).unwrap(),
+            "echo new\n"
+        );
+    }
+}